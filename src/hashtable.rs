@@ -1,58 +1,169 @@
-use std::collections::LinkedList;
-use std::hash::Hasher;
-use std::collections::hash_map::DefaultHasher;
+use std::borrow::Borrow;
+use std::hash::BuildHasher;
+use std::collections::hash_map::RandomState;
 use colored::Colorize;
 use std::ops::Index;
 
 
-#[derive(Debug)]
-struct BucketNode<K, V> {
+struct Slot<K, V> {
+    hash: u64,
     key: K,
     value: V
 }
 
-struct Bucket<K, V> {
-    list: LinkedList<BucketNode<K, V>>
-}
+const DEFAULT_CAPACITY: usize = 16;
+const MAX_LOAD_FACTOR: f64 = 0.75;
+const GROWTH_FACTOR: usize = 2;
 
-impl<K, V> Default for Bucket<K, V> {
-    fn default() -> Bucket<K, V> {
-        Bucket{ list: LinkedList::new() }
+fn round_up_capacity(n: usize) -> usize {
+    let mut cap = DEFAULT_CAPACITY;
+    while cap < n {
+        cap *= 2;
     }
+
+    cap
 }
 
-const DEFAULT_CAPACITY: usize = 16;
+fn probe_distance(hash: u64, idx: usize, cap: usize) -> usize {
+    let ideal = hash as usize % cap;
+    (idx + cap - ideal) % cap
+}
 
-pub struct HashTable<K, V> 
+pub struct HashTable<K, V, S = RandomState>
 {
-    table: Vec<Bucket<K, V>>,
-    count: usize
+    table: Vec<Option<Slot<K, V>>>,
+    count: usize,
+    hasher: S
 }
 
-impl<K, V> HashTable<K, V> 
+impl<K, V> HashTable<K, V, RandomState>
 where K: Eq + std::hash::Hash
 {
-    pub fn new() -> HashTable<K, V> {
-        let mut container: Vec<Bucket<K, V>> = Vec::new();
-        container.resize_with(DEFAULT_CAPACITY, Default::default);
+    pub fn new() -> HashTable<K, V, RandomState> {
+        HashTable::with_capacity(DEFAULT_CAPACITY)
+    }
 
-        HashTable{ table: container, count: 0 }
+    /// Creates a table with room for at least `n` slots, rounded up to the
+    /// next power of two (never below `DEFAULT_CAPACITY`), hashed with a
+    /// randomly seeded `RandomState` to resist HashDoS-style collision
+    /// flooding.
+    pub fn with_capacity(n: usize) -> HashTable<K, V, RandomState> {
+        HashTable::with_capacity_and_hasher(n, RandomState::new())
+    }
+}
+
+impl<K, V, S> HashTable<K, V, S>
+where K: Eq + std::hash::Hash, S: BuildHasher
+{
+    /// Creates a table using `hasher` to hash keys instead of the default
+    /// randomly seeded `RandomState`, e.g. to plug in a fast non-cryptographic
+    /// hasher for trusted workloads.
+    pub fn with_hasher(hasher: S) -> HashTable<K, V, S> {
+        HashTable::with_capacity_and_hasher(DEFAULT_CAPACITY, hasher)
+    }
+
+    /// Combines `with_capacity` and `with_hasher`.
+    pub fn with_capacity_and_hasher(n: usize, hasher: S) -> HashTable<K, V, S> {
+        let nbuckets = round_up_capacity(n);
+
+        let mut container: Vec<Option<Slot<K, V>>> = Vec::new();
+        container.resize_with(nbuckets, || None);
+
+        HashTable{ table: container, count: 0, hasher }
     }
 
     pub fn insert(&mut self, key: K, value: V) {
-        let hash = HashTable::<K, V>::hash_for(&key);
+        let hash = self.hash_for(&key);
+
+        let (is_new, _idx) = self.insert_slot(Slot { hash, key, value });
+        if is_new {
+            self.count += 1;
+
+            if self.load_factor() > MAX_LOAD_FACTOR {
+                self.resize(self.nbuckets() * GROWTH_FACTOR);
+            }
+        }
+    }
 
-        let pos_in_table = hash as usize % self.nbuckets();
-        let bucket = &mut self.table[pos_in_table];
+    /// Gets the given key's corresponding entry for in-place manipulation,
+    /// in the spirit of `std::collections::HashMap::entry`.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        match self.find_slot(&key) {
+            Some(idx) => Entry::Occupied(OccupiedEntry { table: self, idx }),
+            None => Entry::Vacant(VacantEntry { table: self, key }),
+        }
+    }
 
-        if let Some(node) = bucket.list.iter_mut().find(|node| { node.key == key }) {
-            *node = BucketNode {key, value};
-        } else {
-            bucket.list.push_front(BucketNode {key, value});
-            self.count += 1
+    /// Inserts `incoming` using Robin Hood linear probing: at each occupied
+    /// slot the incoming entry displaces the resident if the resident is
+    /// closer to its ideal bucket, carrying the displaced resident forward
+    /// to be placed later. Returns whether this added a new key (as opposed to
+    /// overwriting an existing one in place) and the index the originally
+    /// passed-in key ends up resting at.
+    fn insert_slot(&mut self, mut incoming: Slot<K, V>) -> (bool, usize) {
+        let cap = self.nbuckets();
+        let mut idx = incoming.hash as usize % cap;
+        let mut dist = 0usize;
+        let mut is_original = true;
+        let mut placed_idx = idx;
+
+        loop {
+            match &mut self.table[idx] {
+                None => {
+                    self.table[idx] = Some(incoming);
+                    if is_original {
+                        placed_idx = idx;
+                    }
+                    return (true, placed_idx);
+                }
+                Some(resident) if resident.key == incoming.key => {
+                    resident.value = incoming.value;
+                    if is_original {
+                        placed_idx = idx;
+                    }
+                    return (false, placed_idx);
+                }
+                Some(resident) => {
+                    let resident_dist = probe_distance(resident.hash, idx, cap);
+
+                    if resident_dist < dist {
+                        std::mem::swap(resident, &mut incoming);
+                        if is_original {
+                            placed_idx = idx;
+                            is_original = false;
+                        }
+                        dist = resident_dist;
+                    }
+                }
+            }
+
+            idx = (idx + 1) % cap;
+            dist += 1;
         }
     }
 
+    /// Removes the slot at `idx` and shifts the following probe chain back
+    /// one position at a time, stopping at an empty slot or one already at
+    /// its ideal bucket, so the Robin Hood probe-distance invariant holds
+    /// without needing tombstones.
+    fn remove_at(&mut self, mut idx: usize) -> (K, V) {
+        let cap = self.nbuckets();
+        let removed = self.table[idx].take().expect("remove_at called on an empty slot");
+        self.count -= 1;
+
+        let mut next = (idx + 1) % cap;
+        while let Some(slot) = &self.table[next] {
+            if slot.hash as usize % cap == next {
+                break;
+            }
+
+            self.table[idx] = self.table[next].take();
+            idx = next;
+            next = (next + 1) % cap;
+        }
+
+        (removed.key, removed.value)
+    }
 
     pub fn len(&self) -> usize {
         self.count
@@ -62,36 +173,387 @@ where K: Eq + std::hash::Hash
         self.table.len()
     }
 
-    pub fn get(&self, key: &K) -> Option<&V> {
-        let hash = HashTable::<K, V>::hash_for(&key);
+    /// Fraction of slots currently occupied, i.e. `count / nbuckets`. Kept
+    /// at or below `MAX_LOAD_FACTOR` by resizing on insert.
+    pub fn load_factor(&self) -> f64 {
+        self.count as f64 / self.nbuckets() as f64
+    }
 
-        let pos_in_table = hash as usize % self.nbuckets();
-        let bucket = &self.table[pos_in_table];
+    pub fn get<Q: ?Sized + std::hash::Hash + Eq>(&self, key: &Q) -> Option<&V>
+    where K: Borrow<Q>
+    {
+        let idx = self.find_slot(key)?;
+        self.table[idx].as_ref().map(|slot| &slot.value)
+    }
 
-        if let Some(node) = bucket.list.iter().find(|node| { node.key == *key }) {
-            Some(&node.value)
-        } else {
-            None
+    pub fn get_mut<Q: ?Sized + std::hash::Hash + Eq>(&mut self, key: &Q) -> Option<&mut V>
+    where K: Borrow<Q>
+    {
+        let idx = self.find_slot(key)?;
+        self.table[idx].as_mut().map(|slot| &mut slot.value)
+    }
+
+    pub fn contains_key<Q: ?Sized + std::hash::Hash + Eq>(&self, key: &Q) -> bool
+    where K: Borrow<Q>
+    {
+        self.find_slot(key).is_some()
+    }
+
+    /// Finds and unlinks `key`'s slot using backward-shift removal, keeping
+    /// the probe-distance invariant intact.
+    pub fn remove<Q: ?Sized + std::hash::Hash + Eq>(&mut self, key: &Q) -> Option<V>
+    where K: Borrow<Q>
+    {
+        let idx = self.find_slot(key)?;
+        Some(self.remove_at(idx).1)
+    }
+
+    /// Empties the table without shrinking it.
+    pub fn clear(&mut self) {
+        for slot in self.table.iter_mut() {
+            *slot = None;
         }
+
+        self.count = 0;
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { inner: self.table.iter() }
     }
 
-    fn hash_for(key: &K) -> u64 {
-        let mut hasher = DefaultHasher::new();
-        key.hash(&mut hasher);
-        hasher.finish()
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut { inner: self.table.iter_mut() }
+    }
+
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { inner: self.iter() }
+    }
+
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { inner: self.iter() }
+    }
+
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut { inner: self.iter_mut() }
+    }
+
+    /// Removes and yields every entry, leaving the table empty without
+    /// shrinking it.
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        self.count = 0;
+        Drain { table: &mut self.table, idx: 0 }
+    }
+
+    /// Walks the probe chain for `key`, stopping early once the distance
+    /// travelled exceeds the resident's own probe distance: Robin Hood's
+    /// invariant guarantees `key` cannot live further along than that.
+    fn find_slot<Q: ?Sized + std::hash::Hash + Eq>(&self, key: &Q) -> Option<usize>
+    where K: Borrow<Q>
+    {
+        let cap = self.nbuckets();
+        let hash = self.hash_for(key);
+        let mut idx = hash as usize % cap;
+        let mut dist = 0usize;
+
+        loop {
+            match &self.table[idx] {
+                None => return None,
+                Some(slot) if slot.key.borrow() == key => return Some(idx),
+                Some(slot) => {
+                    let slot_dist = probe_distance(slot.hash, idx, cap);
+                    if slot_dist < dist {
+                        return None;
+                    }
+                }
+            }
+
+            idx = (idx + 1) % cap;
+            dist += 1;
+        }
+    }
+
+    /// Reallocates the table with `new_nbuckets` slots and reinserts every
+    /// existing entry via Robin Hood probing. `count` is unchanged.
+    fn resize(&mut self, new_nbuckets: usize) {
+        let old_table = std::mem::take(&mut self.table);
+        self.table.resize_with(new_nbuckets, || None);
+
+        for slot in old_table.into_iter().flatten() {
+            self.insert_slot(slot);
+        }
+    }
+
+    fn resize_if_needed_for_one_more(&mut self) {
+        let projected = (self.count + 1) as f64 / self.nbuckets() as f64;
+        if projected > MAX_LOAD_FACTOR {
+            self.resize(self.nbuckets() * GROWTH_FACTOR);
+        }
+    }
+
+    /// Hashes anything `K` can be `Borrow`ed as. Relies on the same
+    /// contract `std::collections::HashMap` does: `Borrow` implementations
+    /// must agree on `Hash` and `Eq` between the owned and borrowed forms.
+    fn hash_for<Q: std::hash::Hash + ?Sized>(&self, key: &Q) -> u64 {
+        self.hasher.hash_one(key)
     }
 }
 
-impl<K, V> Index<&K> for HashTable<K, V> 
-where K: Eq + std::hash::Hash
+impl<K, V, S, Q: ?Sized + std::hash::Hash + Eq> Index<&Q> for HashTable<K, V, S>
+where K: Eq + std::hash::Hash + Borrow<Q>, S: BuildHasher
 {
     type Output = V;
 
-    fn index(&self, key: &K) -> &Self::Output {
+    fn index(&self, key: &Q) -> &Self::Output {
         self.get(key).expect("entry not found")
     }
 }
 
+pub struct Iter<'a, K, V> {
+    inner: std::slice::Iter<'a, Option<Slot<K, V>>>
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().find_map(|slot| slot.as_ref().map(|slot| (&slot.key, &slot.value)))
+    }
+}
+
+pub struct IterMut<'a, K, V> {
+    inner: std::slice::IterMut<'a, Option<Slot<K, V>>>
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().find_map(|slot| slot.as_mut().map(|slot| (&slot.key, &mut slot.value)))
+    }
+}
+
+pub struct Keys<'a, K, V> {
+    inner: Iter<'a, K, V>
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+}
+
+pub struct Values<'a, K, V> {
+    inner: Iter<'a, K, V>
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+pub struct ValuesMut<'a, K, V> {
+    inner: IterMut<'a, K, V>
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+/// Draining iterator that `take`s each occupied slot in place, so the
+/// backing `Vec` (and therefore `nbuckets()`) keeps its length.
+pub struct Drain<'a, K, V> {
+    table: &'a mut Vec<Option<Slot<K, V>>>,
+    idx: usize
+}
+
+impl<'a, K, V> Iterator for Drain<'a, K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.table.len() {
+            let slot = self.table[self.idx].take();
+            self.idx += 1;
+
+            if let Some(slot) = slot {
+                return Some((slot.key, slot.value));
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, K, V> Drop for Drain<'a, K, V> {
+    fn drop(&mut self) {
+        for slot in self.table[self.idx..].iter_mut() {
+            *slot = None;
+        }
+    }
+}
+
+pub struct IntoIter<K, V> {
+    inner: std::vec::IntoIter<Option<Slot<K, V>>>
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().find_map(|slot| slot.map(|slot| (slot.key, slot.value)))
+    }
+}
+
+impl<K, V, S> IntoIterator for HashTable<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { inner: self.table.into_iter() }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HashTable<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter { inner: self.table.iter() }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut HashTable<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut { inner: self.table.iter_mut() }
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for HashTable<K, V, S>
+where K: Eq + std::hash::Hash, S: BuildHasher + Default
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut table = HashTable::with_hasher(S::default());
+        table.extend(iter);
+        table
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for HashTable<K, V, S>
+where K: Eq + std::hash::Hash, S: BuildHasher
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// A view into a single entry in a `HashTable`, obtained from `entry()`.
+pub enum Entry<'a, K, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>)
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S>
+where K: Eq + std::hash::Hash, S: BuildHasher
+{
+    /// Ensures a value is present, inserting `default` if the entry is
+    /// vacant, then returns a mutable reference to it.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default)
+        }
+    }
+
+    /// Like `or_insert`, but computes the default lazily if the entry is
+    /// vacant.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default())
+        }
+    }
+
+    /// Applies `f` to the value if the entry is occupied, then returns the
+    /// entry unchanged for further chaining (e.g. `.and_modify(..).or_insert(..)`).
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+/// A view into an occupied entry in a `HashTable`.
+pub struct OccupiedEntry<'a, K, V, S> {
+    table: &'a mut HashTable<K, V, S>,
+    idx: usize
+}
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S>
+where K: Eq + std::hash::Hash, S: BuildHasher
+{
+    pub fn get(&self) -> &V {
+        &self.table.table[self.idx].as_ref().unwrap().value
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.table.table[self.idx].as_mut().unwrap().value
+    }
+
+    /// Converts into a mutable reference bound to the table's own lifetime.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.table.table[self.idx].as_mut().unwrap().value
+    }
+
+    /// Replaces the value, returning the one that was there before.
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the table, returning its value.
+    pub fn remove(self) -> V {
+        self.table.remove_at(self.idx).1
+    }
+}
+
+/// A view into a vacant entry in a `HashTable`.
+pub struct VacantEntry<'a, K, V, S> {
+    table: &'a mut HashTable<K, V, S>,
+    key: K
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S>
+where K: Eq + std::hash::Hash, S: BuildHasher
+{
+    /// Inserts `value` into the table at this entry's key, returning a
+    /// mutable reference bound to the table's own lifetime.
+    pub fn insert(self, value: V) -> &'a mut V {
+        // Resize ahead of the insert (rather than after, as `insert` does)
+        // so the index `insert_slot` hands back is already final.
+        self.table.resize_if_needed_for_one_more();
+
+        let hash = self.table.hash_for(&self.key);
+        let (_, idx) = self.table.insert_slot(Slot { hash, key: self.key, value });
+        self.table.count += 1;
+
+        &mut self.table.table[idx].as_mut().unwrap().value
+    }
+}
+
 
 
 #[cfg(test)]
@@ -107,6 +569,34 @@ mod tests {
         assert_eq!(ht.nbuckets(), super::DEFAULT_CAPACITY);
     }
 
+    #[test]
+    fn with_capacity_rounds_up() {
+        let ht = HashTable::<i32, i32>::with_capacity(100);
+        assert_eq!(ht.nbuckets(), 128);
+
+        let ht = HashTable::<i32, i32>::with_capacity(1);
+        assert_eq!(ht.nbuckets(), super::DEFAULT_CAPACITY);
+    }
+
+    #[test]
+    fn with_hasher_plugs_in_a_custom_hasher() {
+        #[derive(Default, Clone)]
+        struct FixedStateHasher;
+
+        impl std::hash::BuildHasher for FixedStateHasher {
+            type Hasher = std::collections::hash_map::DefaultHasher;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                std::collections::hash_map::DefaultHasher::new()
+            }
+        }
+
+        let mut ht = HashTable::<i32, i32, FixedStateHasher>::with_hasher(FixedStateHasher);
+        ht.insert(1, 10);
+
+        assert_eq!(ht.get(&1), Some(&10));
+    }
+
     #[test]
     fn insert_changes_len() {
         let mut ht = HashTable::<i32, i32>::new();
@@ -129,6 +619,223 @@ mod tests {
         }
     }
 
+    #[test]
+    fn insert_grows_table_and_keeps_load_factor_bounded() {
+        let mut ht = HashTable::<i32, i32>::new();
+
+        for i in 0..1000 {
+            ht.insert(i, i);
+            assert!(ht.load_factor() <= super::MAX_LOAD_FACTOR);
+        }
+
+        assert_eq!(ht.len(), 1000);
+        for i in 0..1000 {
+            assert_eq!(ht.get(&i), Some(&i));
+        }
+    }
+
+    #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+    struct CollidingKey(i32);
+
+    impl std::hash::Hash for CollidingKey {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            // Force every key into the same ideal bucket so probing has to
+            // do the work of resolving the collision chain.
+            0u64.hash(state);
+        }
+    }
+
+    #[test]
+    fn entry_or_insert_counts_occurrences() {
+        let mut counts = HashTable::<&str, i32>::new();
+
+        for word in ["a", "b", "a", "c", "b", "a"] {
+            *counts.entry(word).or_insert(0) += 1;
+        }
+
+        assert_eq!(counts.get(&"a"), Some(&3));
+        assert_eq!(counts.get(&"b"), Some(&2));
+        assert_eq!(counts.get(&"c"), Some(&1));
+    }
+
+    #[test]
+    fn entry_and_modify_only_touches_occupied() {
+        let mut ht = HashTable::<i32, i32>::new();
+        ht.insert(1, 10);
+
+        ht.entry(1).and_modify(|v| *v += 1).or_insert(0);
+        ht.entry(2).and_modify(|v| *v += 1).or_insert(5);
+
+        assert_eq!(ht.get(&1), Some(&11));
+        assert_eq!(ht.get(&2), Some(&5));
+    }
+
+    #[test]
+    fn occupied_entry_remove() {
+        let mut ht = HashTable::<i32, i32>::new();
+        ht.insert(1, 10);
+
+        let value = match ht.entry(1) {
+            super::Entry::Occupied(entry) => entry.remove(),
+            super::Entry::Vacant(_) => panic!("expected an occupied entry"),
+        };
+
+        assert_eq!(value, 10);
+        assert_eq!(ht.get(&1), None);
+        assert_eq!(ht.len(), 0);
+    }
+
+    #[test]
+    fn remove_unlinks_and_decrements_len() {
+        let mut ht = HashTable::<i32, i32>::new();
+        for i in 0..20 {
+            ht.insert(i, i * 2);
+        }
+
+        for i in 0..20 {
+            assert_eq!(ht.remove(&i), Some(i * 2));
+            assert_eq!(ht.remove(&i), None);
+        }
+
+        assert_eq!(ht.len(), 0);
+        for i in 0..20 {
+            assert!(!ht.contains_key(&i));
+        }
+    }
+
+    #[test]
+    fn remove_preserves_other_entries_in_the_probe_chain() {
+        let mut ht = HashTable::<CollidingKey, i32>::new();
+        for i in 0..10 {
+            ht.insert(CollidingKey(i), i);
+        }
+
+        ht.remove(&CollidingKey(3));
+
+        assert_eq!(ht.get(&CollidingKey(3)), None);
+        for i in (0..10).filter(|&i| i != 3) {
+            assert_eq!(ht.get(&CollidingKey(i)), Some(&i));
+        }
+    }
+
+    #[test]
+    fn get_mut_and_clear() {
+        let mut ht = HashTable::<i32, i32>::new();
+        ht.insert(1, 10);
+
+        *ht.get_mut(&1).unwrap() += 1;
+        assert_eq!(ht.get(&1), Some(&11));
+
+        ht.clear();
+        assert_eq!(ht.len(), 0);
+        assert_eq!(ht.get(&1), None);
+        assert_eq!(ht.nbuckets(), super::DEFAULT_CAPACITY);
+    }
+
+    #[test]
+    fn iteration_visits_every_entry() {
+        let mut ht = HashTable::<i32, i32>::new();
+        for i in 0..20 {
+            ht.insert(i, i * 2);
+        }
+
+        let mut keys: Vec<i32> = ht.keys().copied().collect();
+        keys.sort();
+        assert_eq!(keys, (0..20).collect::<Vec<i32>>());
+
+        let mut values: Vec<i32> = ht.values().copied().collect();
+        values.sort();
+        assert_eq!(values, (0..20).map(|i| i * 2).collect::<Vec<i32>>());
+
+        for value in ht.values_mut() {
+            *value += 1;
+        }
+        let mut pairs: Vec<(i32, i32)> = ht.iter().map(|(k, v)| (*k, *v)).collect();
+        pairs.sort();
+        assert_eq!(pairs, (0..20).map(|i| (i, i * 2 + 1)).collect::<Vec<(i32, i32)>>());
+    }
+
+    #[test]
+    fn drain_empties_table_without_shrinking_it() {
+        let mut ht = HashTable::<i32, i32>::new();
+        for i in 0..20 {
+            ht.insert(i, i * 2);
+        }
+
+        let nbuckets_before = ht.nbuckets();
+        let mut drained: Vec<(i32, i32)> = ht.drain().collect();
+        drained.sort();
+
+        assert_eq!(drained, (0..20).map(|i| (i, i * 2)).collect::<Vec<(i32, i32)>>());
+        assert_eq!(ht.len(), 0);
+        assert_eq!(ht.nbuckets(), nbuckets_before);
+    }
+
+    #[test]
+    fn from_iter_and_extend() {
+        let mut ht: HashTable<i32, i32> = (0..10).map(|i| (i, i * 2)).collect();
+        assert_eq!(ht.len(), 10);
+
+        ht.extend((10..20).map(|i| (i, i * 2)));
+        assert_eq!(ht.len(), 20);
+
+        for i in 0..20 {
+            assert_eq!(ht.get(&i), Some(&(i * 2)));
+        }
+    }
+
+    #[test]
+    fn into_iter_owned_and_borrowed() {
+        let mut ht = HashTable::<i32, i32>::new();
+        ht.insert(1, 10);
+        ht.insert(2, 20);
+
+        let mut borrowed: Vec<(i32, i32)> = (&ht).into_iter().map(|(k, v)| (*k, *v)).collect();
+        borrowed.sort();
+        assert_eq!(borrowed, vec![(1, 10), (2, 20)]);
+
+        let mut owned: Vec<(i32, i32)> = ht.into_iter().collect();
+        owned.sort();
+        assert_eq!(owned, vec![(1, 10), (2, 20)]);
+    }
+
+    #[test]
+    fn borrowed_string_key_lookup() {
+        let mut ht = HashTable::<String, i32>::new();
+        ht.insert(String::from("hello"), 1);
+        ht.insert(String::from("world"), 2);
+
+        assert_eq!(ht.get("hello"), Some(&1));
+        assert_eq!(ht.get("world"), Some(&2));
+        assert_eq!(ht.get("missing"), None);
+        assert!(ht.contains_key("hello"));
+        assert_eq!(ht["hello"], 1);
+
+        assert_eq!(ht.remove("hello"), Some(1));
+        assert_eq!(ht.get("hello"), None);
+    }
+
+    #[test]
+    fn borrowed_slice_key_lookup() {
+        let mut ht = HashTable::<Vec<u8>, i32>::new();
+        ht.insert(vec![1, 2, 3], 42);
+
+        let borrowed: &[u8] = &[1, 2, 3];
+        assert_eq!(ht.get(borrowed), Some(&42));
+    }
+
+    #[test]
+    fn robin_hood_resolves_collisions() {
+        let mut ht = HashTable::<CollidingKey, i32>::new();
+        for i in 0..10 {
+            ht.insert(CollidingKey(i), i);
+        }
+
+        for i in 0..10 {
+            assert_eq!(ht.get(&CollidingKey(i)), Some(&i));
+        }
+    }
+
     #[test]
     fn get_existing_returns_link() {
         let rng = 1..=10;
@@ -180,4 +887,4 @@ mod tests {
 
         ht[&10];
     }
-}
\ No newline at end of file
+}